@@ -33,14 +33,49 @@ pub(crate) fn inv_pow2<F: FieldElement>(n: usize) -> F {
     half_power(log2_n)
 }
 
-fn term_w<F: FieldElement>(m: usize, i: usize, roots: &[F]) -> F {
-    let mut w = F::one();
-    for j in 0..=m {
-        if i != j {
-            w *= roots[i] - roots[j];
+/// Inverts every (nonzero) element of `values` in place using Montgomery's trick:
+/// a single inversion plus `O(n)` multiplications.
+fn batch_invert<F: FieldElement>(values: &mut [F]) {
+    if values.is_empty() {
+        return;
+    }
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acc = F::one();
+    for v in values.iter() {
+        acc *= *v;
+        prefix.push(acc);
+    }
+    let mut running_inv = prefix[prefix.len() - 1].inv();
+    for t in (0..values.len()).rev() {
+        let prev = if t == 0 { F::one() } else { prefix[t - 1] };
+        let inv_t = running_inv * prev;
+        running_inv *= values[t];
+        values[t] = inv_t;
+    }
+}
+
+/// Returns the barycentric denominators `w_i = prod_{j != i} (roots[i] - roots[j])`.
+fn barycentric_denominators<F: FieldElement>(roots: &[F]) -> Vec<F> {
+    let k = roots.len();
+    let mut w = Vec::with_capacity(k);
+    for i in 0..k {
+        let mut wi = F::one();
+        for j in 0..k {
+            if i != j {
+                wi *= roots[i] - roots[j];
+            }
         }
+        w.push(wi);
     }
-    w.inv()
+    w
+}
+
+/// Returns the barycentric weights `1/w_i` for the nodes `roots`, inverting all
+/// denominators together for the cost of a single field inversion.
+pub fn barycentric_weights<F: FieldElement>(roots: &[F]) -> Vec<F> {
+    let mut weights = barycentric_denominators(roots);
+    batch_invert(&mut weights);
+    weights
 }
 
 /// Extends dimension by one, keeping the degree of polynomial unchanged.
@@ -55,11 +90,14 @@ pub fn extend_dimension_one<F: FieldElement>(values: &[F], roots: &[F]) -> F {
             y += *yi * *roots_j;
         }
     } else if k < n {
-        // General case.
-        for (i, yi) in values.iter().enumerate() {
-            y += *yi * term_w(k, i, roots);
+        // General case: one inversion for all weights; keep w_k raw for the normalization.
+        let mut weights = barycentric_denominators(&roots[..=k]);
+        let w_k = weights[k];
+        batch_invert(&mut weights);
+        for (yi, wi) in values.iter().zip(&weights) {
+            y += *yi * *wi;
         }
-        y *= -term_w(k, k, roots).inv();
+        y *= -w_k;
     }
     y
 }
@@ -89,6 +127,60 @@ pub fn extend_dimension_double<F: NttFriendlyFieldElement>(values: &mut [F], n:
     perfect_shuffle(&mut values[..2 * n]);
 }
 
+/// Reusable scratch memory for the extend and batched-evaluate routines at a fixed size `n`.
+///
+/// Holds the NTT temporaries, the `1/n` factor, and the precomputed powers of the primitive
+/// `n`-th root of unity, so callers extending or evaluating many vectors of the same size
+/// avoid repeated allocation and root generation.
+pub struct RhizomeScratch<F> {
+    /// Size these temporaries are allocated for; must be a power of two.
+    n: usize,
+    /// Precomputed `1/n`.
+    inv_pow2: F,
+    /// Powers of the primitive `n`-th root of unity, `nth_root_powers[i] = w_n^i`.
+    nth_root_powers: Vec<F>,
+    /// Scratch buffer reused by the inverse NTT pass.
+    ntt_tmp: Vec<F>,
+}
+
+impl<F: NttFriendlyFieldElement> RhizomeScratch<F> {
+    /// Allocates scratch memory for vectors of size `n`, which must be a power of two.
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            inv_pow2: inv_pow2(n),
+            nth_root_powers: nth_root_powers(n),
+            ntt_tmp: vec![F::zero(); n],
+        }
+    }
+
+    /// Returns the size these temporaries were allocated for.
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// Extends dimension by double, reusing the scratch buffer instead of allocating.
+    ///
+    /// Behaves exactly like [`extend_dimension_double`], but borrows the precomputed
+    /// temporaries held by `self`.
+    pub fn extend_dimension_double_with(&mut self, values: &mut [F]) {
+        let n = self.n;
+        assert!(2 * n <= values.len());
+        ntt(&mut self.ntt_tmp, &values[..n], n).unwrap();
+        ntt_inv_finish(&mut self.ntt_tmp, n, self.inv_pow2);
+        ntt_star(&mut values[n..2 * n], &self.ntt_tmp, n).unwrap();
+        perfect_shuffle(&mut values[..2 * n]);
+    }
+
+    /// Evaluates all polynomials in the Lagrange basis, reusing the precomputed roots.
+    ///
+    /// Behaves exactly like [`poly_eval_rhizomes_batched`], but borrows the roots of unity
+    /// held by `self` rather than regenerating them.
+    pub fn poly_eval_rhizomes_batched_with(&self, polynomials: &[Vec<F>], x: F) -> Vec<F> {
+        poly_eval_rhizomes_batched(polynomials, &self.nth_root_powers, x)
+    }
+}
+
 /// Evaluates a polynomial given in the Lagrange basis.
 ///
 /// This is the implementation of Algorithm 6.
@@ -186,6 +278,208 @@ pub fn poly_multieval_rhizomes_batched<F: NttFriendlyFieldElement>(
     }
 }
 
+/// Recovers monomial-basis coefficients from evaluations at the `n`-th roots of unity.
+///
+/// This is the inverse of [`poly_eval_rhizomes`], computed by the inverse NTT. `roots` must
+/// hold the `n` powers of the primitive root, matching `values.len()`.
+pub fn poly_interpolate_rhizomes<F: NttFriendlyFieldElement>(values: &[F], roots: &[F]) -> Vec<F> {
+    let n = values.len();
+    assert_eq!(n, roots.len());
+    let mut coeffs = vec![F::zero(); n];
+    ntt(&mut coeffs, values, n).unwrap();
+    ntt_inv_finish(&mut coeffs, n, inv_pow2(n));
+    coeffs
+}
+
+/// Recovers the monomial-basis coefficients of the polynomial through `(xs[i], ys[i])`.
+///
+/// Uses [`barycentric_weights`] for the node weights `1/w_i` and accumulates each scaled
+/// numerator `ys[i] / w_i * prod_{j != i} (x - xs[j])`.
+pub fn lagrange_interpolate<F: FieldElement>(xs: &[F], ys: &[F]) -> Vec<F> {
+    assert_eq!(xs.len(), ys.len());
+    let k = xs.len();
+    if k == 1 {
+        return vec![ys[0]];
+    }
+
+    let weights = barycentric_weights(xs);
+    let mut coeffs = vec![F::zero(); k];
+    for (i, (&w_i, &y_i)) in weights.iter().zip(ys).enumerate() {
+        // Build the numerator prod_{j != i} (x - xs[j]) incrementally.
+        let mut num = vec![F::zero(); k];
+        num[0] = F::one();
+        let mut deg = 0;
+        for (j, &x_j) in xs.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            deg += 1;
+            for t in (1..=deg).rev() {
+                num[t] = num[t - 1] - x_j * num[t];
+            }
+            num[0] = -x_j * num[0];
+        }
+
+        let scale = w_i * y_i;
+        for (c, coeff) in coeffs.iter_mut().zip(&num) {
+            *c += scale * *coeff;
+        }
+    }
+    coeffs
+}
+
+/// An evaluation domain of size `n` (a power of two) over `F`.
+///
+/// Generalizes the factor-of-two step of [`extend_dimension_double`] into a low-degree
+/// extension of arbitrary power-of-two blowup.
+pub struct Domain<F> {
+    /// Domain size; a power of two.
+    n: usize,
+    /// Precomputed `1/n`.
+    n_inv: F,
+    /// Coset generator used to shift the domain for the low-degree extension.
+    offset: F,
+}
+
+impl<F: NttFriendlyFieldElement> Domain<F> {
+    /// Builds a domain of size `n`, using the field's multiplicative generator as the coset
+    /// offset. `n` must be a power of two.
+    pub fn new(n: usize) -> Self {
+        Self::with_offset(n, F::generator())
+    }
+
+    /// Builds a domain of size `n` with an explicit coset `offset`.
+    pub fn with_offset(n: usize, offset: F) -> Self {
+        Self {
+            n,
+            n_inv: inv_pow2(n),
+            offset,
+        }
+    }
+
+    /// Returns the domain size.
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns `1/n`.
+    pub fn size_inv(&self) -> F {
+        self.n_inv
+    }
+
+    /// Returns the coset generator.
+    pub fn offset(&self) -> F {
+        self.offset
+    }
+
+    /// Returns the forward roots of unity, `w_n^i`.
+    pub fn group_gen_powers(&self) -> Vec<F> {
+        nth_root_powers(self.n)
+    }
+
+    /// Returns the inverse roots of unity, `w_n^{-i}`.
+    pub fn group_gen_inv_powers(&self) -> Vec<F> {
+        // w_n^{-i} = w_n^{n-i}.
+        let roots = nth_root_powers::<F>(self.n);
+        let mut roots_inv = vec![F::one(); self.n];
+        for (i, r) in roots_inv.iter_mut().enumerate().skip(1) {
+            *r = roots[self.n - i];
+        }
+        roots_inv
+    }
+
+    /// Low-degree extension onto a coset of `blowup * n` points.
+    ///
+    /// Interpolates `values` to the monomial basis with a single inverse NTT, then evaluates
+    /// the fixed-degree polynomial over the coset `offset * <w_m>` of size `m = blowup * n`.
+    pub fn coset_lde(&self, values: &[F], blowup: usize) -> Vec<F> {
+        assert_eq!(values.len(), self.n);
+        assert!(blowup.is_power_of_two());
+
+        // Interpolate to the monomial basis.
+        let mut coeffs = vec![F::zero(); self.n];
+        ntt(&mut coeffs, values, self.n).unwrap();
+        ntt_inv_finish(&mut coeffs, self.n, self.n_inv);
+
+        // Pre-multiply by powers of the coset offset, then evaluate over the larger domain.
+        let m = blowup * self.n;
+        let mut shifted = vec![F::zero(); m];
+        let mut pow = F::one();
+        for (dst, c) in shifted.iter_mut().zip(&coeffs) {
+            *dst = *c * pow;
+            pow *= self.offset;
+        }
+        let mut out = vec![F::zero(); m];
+        ntt(&mut out, &shifted, m).unwrap();
+        out
+    }
+}
+
+/// Parallel counterpart of [`poly_eval_rhizomes_batched`].
+///
+/// Parallelizes [`poly_eval_rhizomes_batched`] over the polynomial axis.
+///
+/// The polynomials are chunked across `rayon::current_num_threads()`; each chunk runs
+/// Algorithm 7 over a disjoint set of accumulators, preserving the shared `l`/`d` work per
+/// chunk. The bound matches the serial fn plus `Send + Sync`, required to cross threads.
+#[cfg(feature = "parallel")]
+pub fn poly_eval_rhizomes_batched_parallel<F>(polynomials: &[Vec<F>], roots: &[F], x: F) -> Vec<F>
+where
+    F: FieldElement + Send + Sync,
+{
+    use rayon::prelude::*;
+
+    let chunk = polynomials.len().div_ceil(rayon::current_num_threads()).max(1);
+    polynomials
+        .par_chunks(chunk)
+        .flat_map_iter(|polys| poly_eval_rhizomes_batched(polys, roots, x))
+        .collect()
+}
+
+/// Parallel counterpart of [`poly_multieval_rhizomes_batched`].
+///
+/// Partitions `output_x` across `rayon::current_num_threads()` and processes each chunk in
+/// parallel; the shared product vector `z` is read-only, so the chunks never contend.
+#[cfg(feature = "parallel")]
+pub fn poly_multieval_rhizomes_batched_parallel<F>(output_x: &mut [F], poly: &[F], roots: &[F])
+where
+    F: NttFriendlyFieldElement + Send + Sync,
+{
+    use rayon::prelude::*;
+
+    let n = poly.len();
+    let z = poly[..n]
+        .iter()
+        .zip(&roots[..n])
+        .map(|(yi, wn_i)| *yi * *wn_i)
+        .collect::<Vec<_>>();
+    let num_roots_inv = -inv_pow2::<F>(roots.len());
+
+    let chunk = output_x.len().div_ceil(rayon::current_num_threads()).max(1);
+    output_x.par_chunks_mut(chunk).for_each(|chunk| {
+        for x_j in chunk.iter_mut() {
+            let mut l = F::one();
+            let mut u = poly[0];
+            let mut d = roots[0] - *x_j;
+            for (zi, wn_i) in z[1..n].iter().zip(&roots[1..n]) {
+                l *= d;
+                d = *wn_i - *x_j;
+                u = u * d + l * *zi;
+            }
+
+            for wn_i in &roots[n..] {
+                u *= *wn_i - *x_j;
+            }
+
+            if roots.len() > 1 {
+                u *= num_roots_inv;
+            }
+
+            *x_j = u
+        }
+    });
+}
+
 /// Generates the powers of the primitive n-th root of unity.
 ///
 /// Returns
@@ -270,7 +564,10 @@ mod tests {
     use crate::{
         field::{Field64 as Fp, FieldElement, FieldElementWithInteger},
         rhizomes::test_methods::{nth_root_powers_slow, poly_eval_monomial},
-        rhizomes::{nth_root_powers, poly_eval_rhizomes, poly_eval_rhizomes_batched},
+        rhizomes::{
+            barycentric_weights, extend_dimension_double, nth_root_powers, poly_eval_rhizomes,
+            poly_eval_rhizomes_batched, RhizomeScratch,
+        },
     };
 
     #[test]
@@ -319,6 +616,126 @@ mod tests {
         test_poly_eval_batched(&[1, 6, 3, 9]);
     }
 
+    #[test]
+    fn test_interpolate_rhizomes_roundtrip() {
+        use crate::{
+            polynomial::poly_eval,
+            rhizomes::{lagrange_interpolate, poly_eval_rhizomes, poly_interpolate_rhizomes},
+        };
+
+        for i in 0..8 {
+            let n = 1 << i;
+            let values = Fp::random_vector(n);
+            let roots = nth_root_powers::<Fp>(n);
+            let x = Fp::random_vector(1)[0];
+
+            // Inverse NTT path: coefficients evaluated with Horner must match the
+            // direct Lagrange-basis evaluation.
+            let coeffs = poly_interpolate_rhizomes(&values, &roots);
+            assert_eq!(
+                poly_eval(&coeffs, x),
+                poly_eval_rhizomes(&values, &roots, &x),
+                "n: {n}"
+            );
+
+            // General barycentric path over the same (root) nodes.
+            let coeffs = lagrange_interpolate(&roots, &values);
+            assert_eq!(
+                poly_eval(&coeffs, x),
+                poly_eval_rhizomes(&values, &roots, &x),
+                "n: {n}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_lagrange_interpolate_arbitrary_nodes() {
+        use crate::{polynomial::poly_eval, rhizomes::lagrange_interpolate};
+
+        for k in 1..16usize {
+            let xs = Fp::random_vector(k);
+            let ys = Fp::random_vector(k);
+            let coeffs = lagrange_interpolate(&xs, &ys);
+            for (x, y) in xs.iter().zip(&ys) {
+                assert_eq!(poly_eval(&coeffs, *x), *y, "k: {k}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_coset_lde() {
+        use crate::{
+            ntt::{ntt, ntt_inv_finish},
+            polynomial::poly_eval,
+            rhizomes::{inv_pow2, Domain},
+        };
+
+        for i in 1..6 {
+            let n = 1 << i;
+            let values = Fp::random_vector(n);
+
+            // Reference monomial coefficients via inverse NTT.
+            let mut coeffs = vec![Fp::zero(); n];
+            ntt(&mut coeffs, &values, n).unwrap();
+            ntt_inv_finish(&mut coeffs, n, inv_pow2::<Fp>(n));
+
+            for blowup in [1, 2, 4] {
+                let domain = Domain::<Fp>::new(n);
+                let got = domain.coset_lde(&values, blowup);
+                let m = blowup * n;
+                let w_m = nth_root_powers::<Fp>(m);
+                for (k, g) in got.iter().enumerate() {
+                    let want = poly_eval(&coeffs, domain.offset() * w_m[k]);
+                    assert_eq!(*g, want, "n: {n} blowup: {blowup} k: {k}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_barycentric_weights() {
+        for k in 1..16usize {
+            let roots = Fp::random_vector(k);
+            let got = barycentric_weights(&roots);
+            // Compare against per-node inversion of the denominators.
+            let want = (0..k)
+                .map(|i| {
+                    let mut w = Fp::one();
+                    for j in 0..k {
+                        if i != j {
+                            w *= roots[i] - roots[j];
+                        }
+                    }
+                    w.inv()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(got, want, "k: {k}");
+        }
+    }
+
+    #[test]
+    fn test_rhizome_scratch() {
+        for i in 1..8 {
+            let n = 1 << i;
+            let mut scratch = RhizomeScratch::<Fp>::new(n);
+
+            // The shared scratch reproduces extend_dimension_double.
+            let mut values = Fp::random_vector(2 * n);
+            let mut want = values.clone();
+            extend_dimension_double(&mut want, n);
+            scratch.extend_dimension_double_with(&mut values);
+            assert_eq!(values, want, "n: {n}");
+
+            // The shared scratch reproduces poly_eval_rhizomes_batched.
+            let polynomials = (0..3).map(|_| Fp::random_vector(n)).collect::<Vec<_>>();
+            let x = Fp::random_vector(1)[0];
+            let roots = nth_root_powers(n);
+            let want = poly_eval_rhizomes_batched(&polynomials, &roots, x);
+            let got = scratch.poly_eval_rhizomes_batched_with(&polynomials, x);
+            assert_eq!(got, want, "n: {n}");
+        }
+    }
+
     fn test_poly_eval_batched(lengths: &[usize]) {
         let sizes = lengths
             .iter()
@@ -357,3 +774,41 @@ mod tests {
         assert_eq!(got, want, "sizes: {sizes:?} x: {x} P: {polynomials:?}");
     }
 }
+
+#[cfg(all(test, feature = "parallel"))]
+mod tests_parallel {
+    use crate::{
+        field::{Field64 as Fp, FieldElement},
+        rhizomes::{
+            nth_root_powers, poly_eval_rhizomes_batched, poly_eval_rhizomes_batched_parallel,
+            poly_multieval_rhizomes_batched, poly_multieval_rhizomes_batched_parallel,
+        },
+    };
+
+    #[test]
+    fn test_poly_eval_batched_parallel() {
+        for i in 1..8 {
+            let n = 1 << i;
+            let roots = nth_root_powers(n);
+            let polynomials = (0..5).map(|_| Fp::random_vector(n)).collect::<Vec<_>>();
+            let x = Fp::random_vector(1)[0];
+            let want = poly_eval_rhizomes_batched(&polynomials, &roots, x);
+            let got = poly_eval_rhizomes_batched_parallel(&polynomials, &roots, x);
+            assert_eq!(got, want, "n: {n}");
+        }
+    }
+
+    #[test]
+    fn test_poly_multieval_batched_parallel() {
+        for i in 1..8 {
+            let n = 1 << i;
+            let roots = nth_root_powers(n);
+            let poly = Fp::random_vector(n);
+            let mut want = Fp::random_vector(17);
+            let mut got = want.clone();
+            poly_multieval_rhizomes_batched(&mut want, &poly, &roots);
+            poly_multieval_rhizomes_batched_parallel(&mut got, &poly, &roots);
+            assert_eq!(got, want, "n: {n}");
+        }
+    }
+}